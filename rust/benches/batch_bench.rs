@@ -0,0 +1,35 @@
+// Throughput benchmark for `price_and_greeks_batch` over a 100k-strike chain.
+// Pulls `bsm_greeks.rs` in as a module rather than depending on a separate
+// lib crate, matching this repo's convention of one self-contained file per
+// language; the pricer's types/entry point are `pub(crate)` for exactly this.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../bsm_greeks.rs"]
+mod bsm_greeks;
+use bsm_greeks::{price_and_greeks_batch, BSMInputs};
+
+fn option_chain(n: usize) -> Vec<BSMInputs> {
+    (0..n)
+        .map(|i| BSMInputs {
+            s0: 100.0,
+            k: 50.0 + (i % 100) as f64,
+            t: 0.5,
+            sigma: 0.20,
+            r: 0.03,
+            q: 0.01,
+            opt_type: if i % 2 == 0 { "call".to_string() } else { "put".to_string() },
+            opt_style: "european".to_string(),
+        })
+        .collect()
+}
+
+fn bench_batch_100k(c: &mut Criterion) {
+    let chain = option_chain(100_000);
+    c.bench_function("price_and_greeks_batch/100k", |b| {
+        b.iter(|| price_and_greeks_batch(black_box(&chain)))
+    });
+}
+
+criterion_group!(benches, bench_batch_100k);
+criterion_main!(benches);